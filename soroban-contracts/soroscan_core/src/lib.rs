@@ -1,13 +1,27 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map,
-    Symbol,
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN,
+    Env, Map, Symbol, Vec,
 };
 
 // Storage keys
 const ADMIN_KEY: Symbol = symbol_short!("admin");
 const INDEXERS_KEY: Symbol = symbol_short!("idxrs");
 const COUNTER_KEY: Symbol = symbol_short!("count");
+const TYPE_COUNT_KEY: Symbol = symbol_short!("n");
+const BATCHES_KEY: Symbol = symbol_short!("batches");
+const BATCH_COUNT_KEY: Symbol = symbol_short!("batchn");
+const PENDING_ADMIN_KEY: Symbol = symbol_short!("pendadm");
+
+/// Maximum number of records `events_by_type` will return in a single call,
+/// regardless of the requested `limit`, to bound CPU/memory.
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// Minimum remaining TTL (in ledgers) before a touched persistent entry is
+/// extended back out to `BUMP_AMOUNT`.
+const BUMP_THRESHOLD: u32 = 100_800; // ~7 days at 6s ledgers
+/// TTL (in ledgers) persistent entries are extended to when touched.
+const BUMP_AMOUNT: u32 = 518_400; // ~36 days at 6s ledgers
 
 /// Represents a recorded event from an indexed contract.
 #[contracttype]
@@ -25,6 +39,62 @@ pub struct EventRecord {
     pub timestamp: u64,
 }
 
+/// Where an indexer should start (or resume) reading events from.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StartBlock {
+    /// Start from a specific ledger sequence.
+    Height(u32),
+    /// Start from whatever the current ledger sequence is at resume time.
+    Latest,
+    /// Resume from the last ledger this indexer recorded an event at.
+    Interruption,
+}
+
+/// Per-indexer configuration and progress, so a restarting indexer can
+/// deterministically figure out where it left off.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexerState {
+    /// How this indexer determines its resume point.
+    pub start_block: StartBlock,
+    /// Ledger sequence of the last event this indexer recorded.
+    pub last_ledger: u32,
+    /// Total number of events this indexer has recorded.
+    pub events_recorded: u64,
+    /// Event types this indexer may record. Empty means unrestricted.
+    pub scope: Vec<Symbol>,
+}
+
+/// Check whether `event_type` falls within an indexer's allowlist.
+/// An empty scope means the indexer is unrestricted.
+fn event_type_allowed(scope: &Vec<Symbol>, event_type: &Symbol) -> bool {
+    if scope.is_empty() {
+        return true;
+    }
+    for allowed in scope.iter() {
+        if &allowed == event_type {
+            return true;
+        }
+    }
+    false
+}
+
+/// Check whether every event type in `requested` is also present in
+/// `parent_scope`. An empty `parent_scope` is unrestricted and allows
+/// anything.
+fn scope_within(parent_scope: &Vec<Symbol>, requested: &Vec<Symbol>) -> bool {
+    if parent_scope.is_empty() {
+        return true;
+    }
+    for event_type in requested.iter() {
+        if !event_type_allowed(parent_scope, &event_type) {
+            return false;
+        }
+    }
+    true
+}
+
 /// Contract errors with explicit error codes.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -37,6 +107,44 @@ pub enum ContractError {
     AlreadyInitialized = 3,
     /// Contract has not been initialized.
     NotInitialized = 4,
+    /// A batch operation was called with no entries.
+    EmptyBatch = 5,
+    /// The indexer is not scoped to record this event type.
+    EventTypeNotAllowed = 6,
+    /// There is no pending admin transfer to accept.
+    NoPendingAdmin = 7,
+    /// An indexer with this address is already registered.
+    IndexerAlreadyExists = 8,
+}
+
+/// Hash a pair of Merkle tree nodes into their parent, matching the
+/// concatenation order used by `record_events_batch`/`verify_event_in_batch`.
+fn hash_pair(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+    let mut combined: Bytes = left.clone().into();
+    combined.append(&right.clone().into());
+    env.crypto().sha256(&combined).into()
+}
+
+/// Build a Merkle root over a batch's leaf hashes, duplicating the last
+/// node of any level with an odd number of entries.
+fn merkle_root(env: &Env, leaves: &Vec<BytesN<32>>) -> BytesN<32> {
+    let mut level = leaves.clone();
+    while level.len() > 1 {
+        let mut next = Vec::new(env);
+        let mut i = 0u32;
+        while i < level.len() {
+            let left = level.get(i).unwrap();
+            let right = if i + 1 < level.len() {
+                level.get(i + 1).unwrap()
+            } else {
+                left.clone()
+            };
+            next.push_back(hash_pair(env, &left, &right));
+            i += 2;
+        }
+        level = next;
+    }
+    level.get(0).unwrap()
 }
 
 #[contract]
@@ -58,7 +166,7 @@ impl SoroScanCore {
         env.storage().instance().set(&ADMIN_KEY, &admin);
         env.storage()
             .instance()
-            .set(&INDEXERS_KEY, &Map::<Address, bool>::new(&env));
+            .set(&INDEXERS_KEY, &Map::<Address, IndexerState>::new(&env));
         env.storage().instance().set(&COUNTER_KEY, &0u64);
 
         Ok(())
@@ -70,7 +178,15 @@ impl SoroScanCore {
     /// * `env` - The contract environment
     /// * `admin` - The admin address (must match stored admin)
     /// * `indexer` - The indexer address to authorize
-    pub fn add_indexer(env: Env, admin: Address, indexer: Address) -> Result<(), ContractError> {
+    /// * `start_block` - Where this indexer should (re)start reading events from
+    /// * `scope` - Event types this indexer may record; empty means all types
+    pub fn add_indexer(
+        env: Env,
+        admin: Address,
+        indexer: Address,
+        start_block: StartBlock,
+        scope: Vec<Symbol>,
+    ) -> Result<(), ContractError> {
         admin.require_auth();
 
         let stored_admin: Address = env
@@ -83,13 +199,21 @@ impl SoroScanCore {
             return Err(ContractError::Unauthorized);
         }
 
-        let mut indexers: Map<Address, bool> = env
+        let mut indexers: Map<Address, IndexerState> = env
             .storage()
             .instance()
             .get(&INDEXERS_KEY)
             .ok_or(ContractError::NotInitialized)?;
 
-        indexers.set(indexer.clone(), true);
+        indexers.set(
+            indexer.clone(),
+            IndexerState {
+                start_block,
+                last_ledger: 0,
+                events_recorded: 0,
+                scope,
+            },
+        );
         env.storage().instance().set(&INDEXERS_KEY, &indexers);
 
         // Emit event for indexer addition
@@ -99,6 +223,62 @@ impl SoroScanCore {
         Ok(())
     }
 
+    /// Get the event-type allowlist for an indexer.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `indexer` - The indexer address to query
+    ///
+    /// # Returns
+    /// `Some(scope)` where an empty `Vec` means all event types are allowed,
+    /// or `None` if the indexer is not registered
+    pub fn indexer_scope(env: Env, indexer: Address) -> Option<Vec<Symbol>> {
+        let indexers: Map<Address, IndexerState> = env.storage().instance().get(&INDEXERS_KEY)?;
+        indexers.get(indexer).map(|state| state.scope)
+    }
+
+    /// Update an indexer's event-type allowlist.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - The admin address (must match stored admin)
+    /// * `indexer` - The indexer address to update
+    /// * `scope` - The new allowlist; empty means all event types are allowed
+    pub fn set_indexer_scope(
+        env: Env,
+        admin: Address,
+        indexer: Address,
+        scope: Vec<Symbol>,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&ADMIN_KEY)
+            .ok_or(ContractError::NotInitialized)?;
+
+        if admin != stored_admin {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let mut indexers: Map<Address, IndexerState> = env
+            .storage()
+            .instance()
+            .get(&INDEXERS_KEY)
+            .ok_or(ContractError::NotInitialized)?;
+
+        let mut state = indexers
+            .get(indexer.clone())
+            .ok_or(ContractError::IndexerNotFound)?;
+
+        state.scope = scope;
+        indexers.set(indexer, state);
+        env.storage().instance().set(&INDEXERS_KEY, &indexers);
+
+        Ok(())
+    }
+
     /// Remove an authorized indexer address.
     ///
     /// # Arguments
@@ -118,7 +298,7 @@ impl SoroScanCore {
             return Err(ContractError::Unauthorized);
         }
 
-        let mut indexers: Map<Address, bool> = env
+        let mut indexers: Map<Address, IndexerState> = env
             .storage()
             .instance()
             .get(&INDEXERS_KEY)
@@ -155,15 +335,18 @@ impl SoroScanCore {
     ) -> Result<u64, ContractError> {
         indexer.require_auth();
 
-        let indexers: Map<Address, bool> = env
+        let mut indexers: Map<Address, IndexerState> = env
             .storage()
             .instance()
             .get(&INDEXERS_KEY)
             .ok_or(ContractError::NotInitialized)?;
 
-        let is_allowed = indexers.get(indexer).unwrap_or(false);
-        if !is_allowed {
-            return Err(ContractError::IndexerNotFound);
+        let mut state = indexers
+            .get(indexer.clone())
+            .ok_or(ContractError::IndexerNotFound)?;
+
+        if !event_type_allowed(&state.scope, &event_type) {
+            return Err(ContractError::EventTypeNotAllowed);
         }
 
         let ledger = env.ledger().sequence();
@@ -182,6 +365,27 @@ impl SoroScanCore {
         count = count.saturating_add(1);
         env.storage().instance().set(&COUNTER_KEY, &count);
 
+        // Track this indexer's progress so it can resume after downtime
+        state.last_ledger = ledger;
+        state.events_recorded = state.events_recorded.saturating_add(1);
+        indexers.set(indexer, state);
+        env.storage().instance().set(&INDEXERS_KEY, &indexers);
+
+        // Append to the per-type historical log for paginated queries. Kept
+        // in persistent storage (rather than instance) so the log can grow
+        // without bloating the instance footprint.
+        let seq_key = (event_type.clone(), TYPE_COUNT_KEY);
+        let seq: u64 = env.storage().persistent().get(&seq_key).unwrap_or(0);
+        let entry_key = (event_type.clone(), seq);
+        env.storage().persistent().set(&entry_key, &record);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry_key, BUMP_THRESHOLD, BUMP_AMOUNT);
+        env.storage().persistent().set(&seq_key, &(seq + 1));
+        env.storage()
+            .persistent()
+            .extend_ttl(&seq_key, BUMP_THRESHOLD, BUMP_AMOUNT);
+
         // Store latest event by type
         env.storage().instance().set(&event_type, &record);
 
@@ -192,6 +396,155 @@ impl SoroScanCore {
         Ok(count)
     }
 
+    /// Record a batch of events in one call, authorizing the indexer once,
+    /// and commit a Merkle root over the batch's payload hashes as a
+    /// checkpoint light clients can use to prove membership.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `indexer` - The indexer address (must be authorized)
+    /// * `contract_id` - The contract that emitted the original events
+    /// * `entries` - The `(event_type, payload_hash)` pairs to record, in order
+    ///
+    /// # Returns
+    /// The Merkle root committed for this batch
+    pub fn record_events_batch(
+        env: Env,
+        indexer: Address,
+        contract_id: Address,
+        entries: Vec<(Symbol, BytesN<32>)>,
+    ) -> Result<BytesN<32>, ContractError> {
+        indexer.require_auth();
+
+        if entries.is_empty() {
+            return Err(ContractError::EmptyBatch);
+        }
+
+        let mut indexers: Map<Address, IndexerState> = env
+            .storage()
+            .instance()
+            .get(&INDEXERS_KEY)
+            .ok_or(ContractError::NotInitialized)?;
+
+        let mut state = indexers
+            .get(indexer.clone())
+            .ok_or(ContractError::IndexerNotFound)?;
+
+        let ledger = env.ledger().sequence();
+        let timestamp = env.ledger().timestamp();
+
+        let mut count: u64 = env.storage().instance().get(&COUNTER_KEY).unwrap_or(0);
+        let mut leaves = Vec::new(&env);
+
+        for (event_type, payload_hash) in entries.iter() {
+            if !event_type_allowed(&state.scope, &event_type) {
+                return Err(ContractError::EventTypeNotAllowed);
+            }
+
+            let record = EventRecord {
+                contract_id: contract_id.clone(),
+                event_type: event_type.clone(),
+                payload_hash: payload_hash.clone(),
+                ledger,
+                timestamp,
+            };
+
+            count = count.saturating_add(1);
+
+            let seq_key = (event_type.clone(), TYPE_COUNT_KEY);
+            let seq: u64 = env.storage().persistent().get(&seq_key).unwrap_or(0);
+            let entry_key = (event_type.clone(), seq);
+            env.storage().persistent().set(&entry_key, &record);
+            env.storage()
+                .persistent()
+                .extend_ttl(&entry_key, BUMP_THRESHOLD, BUMP_AMOUNT);
+            env.storage().persistent().set(&seq_key, &(seq + 1));
+            env.storage()
+                .persistent()
+                .extend_ttl(&seq_key, BUMP_THRESHOLD, BUMP_AMOUNT);
+
+            env.storage().instance().set(&event_type, &record);
+
+            leaves.push_back(payload_hash);
+        }
+
+        env.storage().instance().set(&COUNTER_KEY, &count);
+
+        state.last_ledger = ledger;
+        state.events_recorded = state.events_recorded.saturating_add(entries.len() as u64);
+        indexers.set(indexer, state);
+        env.storage().instance().set(&INDEXERS_KEY, &indexers);
+
+        let root = merkle_root(&env, &leaves);
+
+        let batch_index: u64 = env
+            .storage()
+            .persistent()
+            .get(&BATCH_COUNT_KEY)
+            .unwrap_or(0);
+        let checkpoint_key = (BATCHES_KEY, batch_index);
+        env.storage().persistent().set(&checkpoint_key, &root);
+        env.storage()
+            .persistent()
+            .extend_ttl(&checkpoint_key, BUMP_THRESHOLD, BUMP_AMOUNT);
+        env.storage()
+            .persistent()
+            .set(&BATCH_COUNT_KEY, &(batch_index + 1));
+        env.storage()
+            .persistent()
+            .extend_ttl(&BATCH_COUNT_KEY, BUMP_THRESHOLD, BUMP_AMOUNT);
+
+        // Publish the checkpoint for off-chain indexers
+        env.events().publish(
+            (symbol_short!("soroscan"), symbol_short!("batch")),
+            (batch_index, root.clone()),
+        );
+
+        Ok(root)
+    }
+
+    /// Verify that a leaf (payload hash) was included in a recorded batch,
+    /// without needing the full log — just the sibling hashes on its path
+    /// to the checkpoint root.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `batch_index` - The batch to verify against, as published alongside its root
+    ///   in the `("soroscan", "batch")` event
+    /// * `leaf` - The payload hash being proven
+    /// * `index` - The leaf's 0-based position in the batch
+    /// * `proof` - Sibling hashes from the leaf up to the root
+    ///
+    /// # Returns
+    /// `true` if the recomputed root matches the stored checkpoint
+    pub fn verify_event_in_batch(
+        env: Env,
+        batch_index: u64,
+        leaf: BytesN<32>,
+        index: u32,
+        proof: Vec<BytesN<32>>,
+    ) -> bool {
+        let checkpoint_key = (BATCHES_KEY, batch_index);
+        let stored_root: Option<BytesN<32>> = env.storage().persistent().get(&checkpoint_key);
+        let stored_root = match stored_root {
+            Some(root) => root,
+            None => return false,
+        };
+
+        let mut computed = leaf;
+        let mut idx = index;
+        for sibling in proof.iter() {
+            computed = if idx & 1 == 0 {
+                hash_pair(&env, &computed, &sibling)
+            } else {
+                hash_pair(&env, &sibling, &computed)
+            };
+            idx /= 2;
+        }
+
+        computed == stored_root
+    }
+
     /// Get the latest event record for a specific event type.
     ///
     /// # Arguments
@@ -215,6 +568,55 @@ impl SoroScanCore {
         env.storage().instance().get(&COUNTER_KEY).unwrap_or(0)
     }
 
+    /// Get the number of historical events recorded for a given type.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `event_type` - The event type to query
+    ///
+    /// # Returns
+    /// The number of events of this type stored in the historical log
+    pub fn count_by_type(env: Env, event_type: Symbol) -> u64 {
+        let seq_key = (event_type, TYPE_COUNT_KEY);
+        env.storage().persistent().get(&seq_key).unwrap_or(0)
+    }
+
+    /// Get a page of historical events for a given type, oldest first.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `event_type` - The event type to query
+    /// * `start` - The 0-based sequence number to start at
+    /// * `limit` - Maximum number of records to return, clamped to `MAX_PAGE_SIZE`
+    ///
+    /// # Returns
+    /// Up to `limit` records starting at `start`, in recording order
+    pub fn events_by_type(
+        env: Env,
+        event_type: Symbol,
+        start: u64,
+        limit: u32,
+    ) -> Vec<EventRecord> {
+        let count = Self::count_by_type(env.clone(), event_type.clone());
+        let limit = limit.min(MAX_PAGE_SIZE) as u64;
+        let end = start.saturating_add(limit).min(count);
+
+        let mut results = Vec::new(&env);
+        let mut seq = start;
+        while seq < end {
+            let entry_key = (event_type.clone(), seq);
+            if let Some(record) = env.storage().persistent().get(&entry_key) {
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&entry_key, BUMP_THRESHOLD, BUMP_AMOUNT);
+                results.push_back(record);
+            }
+            seq += 1;
+        }
+
+        results
+    }
+
     /// Check if an address is an authorized indexer.
     ///
     /// # Arguments
@@ -224,13 +626,34 @@ impl SoroScanCore {
     /// # Returns
     /// true if the address is authorized, false otherwise
     pub fn is_indexer(env: Env, indexer: Address) -> bool {
-        let indexers: Option<Map<Address, bool>> = env.storage().instance().get(&INDEXERS_KEY);
+        let indexers: Option<Map<Address, IndexerState>> =
+            env.storage().instance().get(&INDEXERS_KEY);
         match indexers {
-            Some(map) => map.get(indexer).unwrap_or(false),
+            Some(map) => map.contains_key(indexer),
             None => false,
         }
     }
 
+    /// Get the ledger sequence an indexer should resume reading events from.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `indexer` - The indexer address to query
+    ///
+    /// # Returns
+    /// `Some(ledger)` computed from the indexer's `StartBlock` mode, or
+    /// `None` if the indexer is not registered.
+    pub fn resume_point(env: Env, indexer: Address) -> Option<u32> {
+        let indexers: Map<Address, IndexerState> = env.storage().instance().get(&INDEXERS_KEY)?;
+        let state = indexers.get(indexer)?;
+
+        Some(match state.start_block {
+            StartBlock::Height(height) => height,
+            StartBlock::Latest => env.ledger().sequence(),
+            StartBlock::Interruption => state.last_ledger,
+        })
+    }
+
     /// Get the admin address.
     ///
     /// # Arguments
@@ -241,6 +664,139 @@ impl SoroScanCore {
     pub fn get_admin(env: Env) -> Option<Address> {
         env.storage().instance().get(&ADMIN_KEY)
     }
+
+    /// Propose a new admin. Takes effect once `new_admin` calls
+    /// `accept_admin`, so a typo or unreachable address can't lock out the
+    /// contract. Works whether `admin`/`new_admin` are classic accounts or
+    /// custom/multisig account contracts, since both authorize via
+    /// `require_auth`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - The current admin address (must match stored admin)
+    /// * `new_admin` - The address proposed to become the new admin
+    pub fn propose_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&ADMIN_KEY)
+            .ok_or(ContractError::NotInitialized)?;
+
+        if admin != stored_admin {
+            return Err(ContractError::Unauthorized);
+        }
+
+        env.storage()
+            .instance()
+            .set(&PENDING_ADMIN_KEY, &new_admin);
+
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("propose")),
+            new_admin,
+        );
+
+        Ok(())
+    }
+
+    /// Accept a pending admin transfer proposed via `propose_admin`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `new_admin` - The address accepting the admin role (must match the pending proposal)
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        new_admin.require_auth();
+
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&PENDING_ADMIN_KEY)
+            .ok_or(ContractError::NoPendingAdmin)?;
+
+        if pending != new_admin {
+            return Err(ContractError::Unauthorized);
+        }
+
+        env.storage().instance().set(&ADMIN_KEY, &new_admin);
+        env.storage().instance().remove(&PENDING_ADMIN_KEY);
+
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("accept")),
+            new_admin,
+        );
+
+        Ok(())
+    }
+
+    /// Register a sub-indexer controlled by an already-authorized indexer,
+    /// without requiring the contract admin. Lets a contract-account indexer
+    /// (e.g. one whose `__check_auth` delegates to a fleet of workers)
+    /// delegate recording rights to sub-indexers it manages.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `indexer` - The already-authorized parent indexer (must call `require_auth`)
+    /// * `sub_indexer` - The new indexer address to authorize
+    /// * `start_block` - Where the sub-indexer should (re)start reading events from
+    /// * `scope` - Event types the sub-indexer may record; must stay within the
+    ///   parent's own scope, and empty inherits the parent's scope as-is rather
+    ///   than widening it to unrestricted
+    pub fn add_indexer_delegated(
+        env: Env,
+        indexer: Address,
+        sub_indexer: Address,
+        start_block: StartBlock,
+        scope: Vec<Symbol>,
+    ) -> Result<(), ContractError> {
+        indexer.require_auth();
+
+        let mut indexers: Map<Address, IndexerState> = env
+            .storage()
+            .instance()
+            .get(&INDEXERS_KEY)
+            .ok_or(ContractError::NotInitialized)?;
+
+        let parent_state = indexers
+            .get(indexer)
+            .ok_or(ContractError::IndexerNotFound)?;
+
+        if indexers.contains_key(sub_indexer.clone()) {
+            return Err(ContractError::IndexerAlreadyExists);
+        }
+
+        // A delegated sub-indexer can never see more than its parent: an
+        // empty request inherits the parent's own scope rather than
+        // widening to unrestricted, and an explicit request must stay
+        // within the parent's allowlist.
+        if !scope.is_empty() && !scope_within(&parent_state.scope, &scope) {
+            return Err(ContractError::EventTypeNotAllowed);
+        }
+        let sub_scope = if scope.is_empty() {
+            parent_state.scope.clone()
+        } else {
+            scope
+        };
+
+        indexers.set(
+            sub_indexer.clone(),
+            IndexerState {
+                start_block,
+                last_ledger: 0,
+                events_recorded: 0,
+                scope: sub_scope,
+            },
+        );
+        env.storage().instance().set(&INDEXERS_KEY, &indexers);
+
+        // Emit event for delegated indexer addition
+        env.events().publish(
+            (symbol_short!("indexer"), symbol_short!("deleg")),
+            sub_indexer,
+        );
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -277,13 +833,54 @@ mod tests {
 
         assert!(!client.is_indexer(&indexer));
 
-        client.add_indexer(&admin, &indexer);
+        client.add_indexer(&admin, &indexer, &StartBlock::Latest, &Vec::new(&env));
         assert!(client.is_indexer(&indexer));
 
         client.remove_indexer(&admin, &indexer);
         assert!(!client.is_indexer(&indexer));
     }
 
+    #[test]
+    fn test_resume_point() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, SoroScanCore);
+        let client = SoroScanCoreClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let target_contract = Address::generate(&env);
+        let height_indexer = Address::generate(&env);
+        let latest_indexer = Address::generate(&env);
+        let interruption_indexer = Address::generate(&env);
+
+        client.init(&admin);
+        client.add_indexer(&admin, &height_indexer, &StartBlock::Height(42), &Vec::new(&env));
+        client.add_indexer(&admin, &latest_indexer, &StartBlock::Latest, &Vec::new(&env));
+        client.add_indexer(&admin, &interruption_indexer, &StartBlock::Interruption, &Vec::new(&env));
+
+        assert_eq!(client.resume_point(&height_indexer), Some(42));
+        assert_eq!(
+            client.resume_point(&latest_indexer),
+            Some(env.ledger().sequence())
+        );
+        assert_eq!(client.resume_point(&interruption_indexer), Some(0));
+
+        let event_type = symbol_short!("swap");
+        let payload_hash = BytesN::from_array(&env, &[0u8; 32]);
+        client.record_event(
+            &interruption_indexer,
+            &target_contract,
+            &event_type,
+            &payload_hash,
+        );
+
+        assert_eq!(
+            client.resume_point(&interruption_indexer),
+            Some(env.ledger().sequence())
+        );
+    }
+
     #[test]
     fn test_record_event() {
         let env = Env::default();
@@ -297,7 +894,7 @@ mod tests {
         let target_contract = Address::generate(&env);
 
         client.init(&admin);
-        client.add_indexer(&admin, &indexer);
+        client.add_indexer(&admin, &indexer, &StartBlock::Latest, &Vec::new(&env));
 
         let event_type = symbol_short!("swap");
         let payload_hash = BytesN::from_array(&env, &[0u8; 32]);
@@ -311,6 +908,142 @@ mod tests {
         assert_eq!(latest.unwrap().event_type, event_type);
     }
 
+    #[test]
+    fn test_events_by_type_pagination() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, SoroScanCore);
+        let client = SoroScanCoreClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let indexer = Address::generate(&env);
+        let target_contract = Address::generate(&env);
+
+        client.init(&admin);
+        client.add_indexer(&admin, &indexer, &StartBlock::Latest, &Vec::new(&env));
+
+        let event_type = symbol_short!("swap");
+        let other_type = symbol_short!("mint");
+        let payload_hash = BytesN::from_array(&env, &[0u8; 32]);
+
+        for _ in 0..5 {
+            client.record_event(&indexer, &target_contract, &event_type, &payload_hash);
+        }
+        client.record_event(&indexer, &target_contract, &other_type, &payload_hash);
+
+        assert_eq!(client.count_by_type(&event_type), 5);
+        assert_eq!(client.count_by_type(&other_type), 1);
+
+        let page = client.events_by_type(&event_type, &1, &2);
+        assert_eq!(page.len(), 2);
+
+        let all = client.events_by_type(&event_type, &0, &100);
+        assert_eq!(all.len(), 5);
+
+        let none = client.events_by_type(&event_type, &5, &10);
+        assert_eq!(none.len(), 0);
+    }
+
+    #[test]
+    fn test_record_events_batch_and_verify() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, SoroScanCore);
+        let client = SoroScanCoreClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let indexer = Address::generate(&env);
+        let target_contract = Address::generate(&env);
+
+        client.init(&admin);
+        client.add_indexer(&admin, &indexer, &StartBlock::Latest, &Vec::new(&env));
+
+        let swap = symbol_short!("swap");
+        let mint = symbol_short!("mint");
+        let burn = symbol_short!("burn");
+
+        let h0 = BytesN::from_array(&env, &[1u8; 32]);
+        let h1 = BytesN::from_array(&env, &[2u8; 32]);
+        let h2 = BytesN::from_array(&env, &[3u8; 32]);
+
+        let mut entries = Vec::new(&env);
+        entries.push_back((swap, h0.clone()));
+        entries.push_back((mint, h1.clone()));
+        entries.push_back((burn, h2.clone()));
+
+        let root = client.record_events_batch(&indexer, &target_contract, &entries);
+
+        let mut leaves = Vec::new(&env);
+        leaves.push_back(h0.clone());
+        leaves.push_back(h1.clone());
+        leaves.push_back(h2.clone());
+        assert_eq!(root, merkle_root(&env, &leaves));
+
+        // Odd-length level: the last node (h2) pairs with itself.
+        let mut proof = Vec::new(&env);
+        proof.push_back(h1.clone());
+        proof.push_back(hash_pair(&env, &h2, &h2));
+
+        assert!(client.verify_event_in_batch(&0u64, &h0, &0u32, &proof));
+        assert!(!client.verify_event_in_batch(&0u64, &h0, &1u32, &proof));
+        assert!(!client.verify_event_in_batch(&1u64, &h0, &0u32, &proof));
+    }
+
+    #[test]
+    fn test_record_events_batch_rejects_empty() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, SoroScanCore);
+        let client = SoroScanCoreClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let indexer = Address::generate(&env);
+        let target_contract = Address::generate(&env);
+
+        client.init(&admin);
+        client.add_indexer(&admin, &indexer, &StartBlock::Latest, &Vec::new(&env));
+
+        let entries: Vec<(Symbol, BytesN<32>)> = Vec::new(&env);
+        let result = client.try_record_events_batch(&indexer, &target_contract, &entries);
+        assert_eq!(result, Err(Ok(ContractError::EmptyBatch)));
+    }
+
+    #[test]
+    fn test_indexer_scope_restricts_event_types() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, SoroScanCore);
+        let client = SoroScanCoreClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let indexer = Address::generate(&env);
+        let target_contract = Address::generate(&env);
+
+        let swap = symbol_short!("swap");
+        let mint = symbol_short!("mint");
+        let payload_hash = BytesN::from_array(&env, &[0u8; 32]);
+
+        client.init(&admin);
+
+        let mut scope = Vec::new(&env);
+        scope.push_back(swap.clone());
+        client.add_indexer(&admin, &indexer, &StartBlock::Latest, &scope);
+
+        assert_eq!(client.indexer_scope(&indexer), Some(scope.clone()));
+
+        client.record_event(&indexer, &target_contract, &swap, &payload_hash);
+
+        let result = client.try_record_event(&indexer, &target_contract, &mint, &payload_hash);
+        assert_eq!(result, Err(Ok(ContractError::EventTypeNotAllowed)));
+
+        client.set_indexer_scope(&admin, &indexer, &Vec::new(&env));
+        client.record_event(&indexer, &target_contract, &mint, &payload_hash);
+    }
+
     #[test]
     fn test_add_indexer_as_non_admin() {
         let env = Env::default();
@@ -326,7 +1059,7 @@ mod tests {
         client.init(&admin);
 
         // Non-admin tries to add indexer — should fail with Unauthorized
-        let result = client.try_add_indexer(&non_admin, &indexer);
+        let result = client.try_add_indexer(&non_admin, &indexer, &StartBlock::Latest, &Vec::new(&env));
         assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
     }
 
@@ -365,4 +1098,138 @@ mod tests {
         let result = client.try_init(&admin);
         assert_eq!(result, Err(Ok(ContractError::AlreadyInitialized)));
     }
+
+    #[test]
+    fn test_propose_and_accept_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, SoroScanCore);
+        let client = SoroScanCoreClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+
+        client.init(&admin);
+
+        client.propose_admin(&admin, &new_admin);
+        client.accept_admin(&new_admin);
+
+        assert_eq!(client.get_admin(), Some(new_admin));
+    }
+
+    #[test]
+    fn test_accept_admin_without_proposal() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, SoroScanCore);
+        let client = SoroScanCoreClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+
+        client.init(&admin);
+
+        let result = client.try_accept_admin(&new_admin);
+        assert_eq!(result, Err(Ok(ContractError::NoPendingAdmin)));
+    }
+
+    #[test]
+    fn test_add_indexer_delegated() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, SoroScanCore);
+        let client = SoroScanCoreClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let indexer = Address::generate(&env);
+        let sub_indexer = Address::generate(&env);
+        let outsider = Address::generate(&env);
+
+        client.init(&admin);
+        client.add_indexer(&admin, &indexer, &StartBlock::Latest, &Vec::new(&env));
+
+        client.add_indexer_delegated(
+            &indexer,
+            &sub_indexer,
+            &StartBlock::Latest,
+            &Vec::new(&env),
+        );
+        assert!(client.is_indexer(&sub_indexer));
+
+        // An unregistered indexer can't delegate sub-indexers of its own
+        let result = client.try_add_indexer_delegated(
+            &outsider,
+            &sub_indexer,
+            &StartBlock::Latest,
+            &Vec::new(&env),
+        );
+        assert_eq!(result, Err(Ok(ContractError::IndexerNotFound)));
+    }
+
+    #[test]
+    fn test_add_indexer_delegated_cannot_exceed_parent_scope() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, SoroScanCore);
+        let client = SoroScanCoreClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let indexer = Address::generate(&env);
+        let sub_indexer = Address::generate(&env);
+
+        let swap = symbol_short!("swap");
+        let mint = symbol_short!("mint");
+
+        client.init(&admin);
+
+        let mut parent_scope = Vec::new(&env);
+        parent_scope.push_back(swap.clone());
+        client.add_indexer(&admin, &indexer, &StartBlock::Latest, &parent_scope);
+
+        // Requesting a scope outside the parent's own is rejected
+        let mut requested_scope = Vec::new(&env);
+        requested_scope.push_back(mint);
+        let result = client.try_add_indexer_delegated(
+            &indexer,
+            &sub_indexer,
+            &StartBlock::Latest,
+            &requested_scope,
+        );
+        assert_eq!(result, Err(Ok(ContractError::EventTypeNotAllowed)));
+
+        // An empty request inherits the parent's scope rather than
+        // widening to unrestricted
+        client.add_indexer_delegated(&indexer, &sub_indexer, &StartBlock::Latest, &Vec::new(&env));
+        assert_eq!(client.indexer_scope(&sub_indexer), Some(parent_scope));
+    }
+
+    #[test]
+    fn test_add_indexer_delegated_rejects_existing_indexer() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, SoroScanCore);
+        let client = SoroScanCoreClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let indexer_a = Address::generate(&env);
+        let indexer_b = Address::generate(&env);
+
+        client.init(&admin);
+        client.add_indexer(&admin, &indexer_a, &StartBlock::Latest, &Vec::new(&env));
+        client.add_indexer(&admin, &indexer_b, &StartBlock::Latest, &Vec::new(&env));
+
+        // indexer_a can't silently take over an indexer admin already manages
+        let result = client.try_add_indexer_delegated(
+            &indexer_a,
+            &indexer_b,
+            &StartBlock::Latest,
+            &Vec::new(&env),
+        );
+        assert_eq!(result, Err(Ok(ContractError::IndexerAlreadyExists)));
+    }
 }